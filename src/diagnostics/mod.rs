@@ -0,0 +1,94 @@
+use crate::lexer::Span;
+
+// Renders a self-contained, multi-line diagnostic: a gutter with the
+// offending line(s) from `source`, followed by a caret/underline spanning
+// `span`, e.g.:
+//
+//   example.st:2:1: error: unterminated string constant: hello
+//   2 | 'hello
+//     | ^^^^^^
+pub fn render(source: &str, filename: &str, span: Span, message: &str) -> String {
+    let lines: Vec<&str> = source.lines().collect();
+    let last_line = lines.len().max(1);
+
+    let start_line = (span.start_line as usize).clamp(1, last_line);
+    let end_line = (span.end_line as usize).clamp(start_line, last_line);
+    let gutter_width = end_line.to_string().len();
+
+    let mut output = format!(
+        "{}:{}:{}: error: {}\n",
+        filename, span.start_line, span.start_col, message
+    );
+
+    for line_no in start_line..=end_line {
+        let text = lines.get(line_no - 1).copied().unwrap_or("");
+        output.push_str(&format!("{:>gutter_width$} | {}\n", line_no, text));
+
+        let underline_start = if line_no == start_line {
+            span.start_col as usize
+        } else {
+            1
+        };
+        let underline_end = if line_no == end_line {
+            (span.end_col as usize).max(underline_start + 1)
+        } else {
+            text.chars().count() + 1
+        };
+
+        output.push_str(&format!(
+            "{:gutter_width$} | {}{}\n",
+            "",
+            " ".repeat(underline_start.saturating_sub(1)),
+            "^".repeat(underline_end - underline_start),
+        ));
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_single_line_span() {
+        let source = "foo := 'unterminated\n";
+        let span = Span {
+            start_line: 1,
+            start_col: 8,
+            end_line: 1,
+            end_col: 9,
+        };
+
+        let rendered = render(source, "unterminated.st", span, "unterminated string constant");
+
+        assert_eq!(
+            "unterminated.st:1:8: error: unterminated string constant\n\
+             1 | foo := 'unterminated\n\
+             \u{20} |        ^\n",
+            rendered
+        );
+    }
+
+    #[test]
+    fn test_render_multi_line_span() {
+        let source = "\"a comment\nspanning lines\" bar\n";
+        let span = Span {
+            start_line: 1,
+            start_col: 1,
+            end_line: 2,
+            end_col: 16,
+        };
+
+        let rendered = render(source, "comments.st", span, "unterminated comment");
+
+        assert_eq!(
+            "comments.st:1:1: error: unterminated comment\n\
+             1 | \"a comment\n\
+             \u{20} | ^^^^^^^^^^\n\
+             2 | spanning lines\" bar\n\
+             \u{20} | ^^^^^^^^^^^^^^^\n",
+            rendered
+        );
+    }
+}
@@ -2,10 +2,94 @@
 
 use std::fs;
 use std::io;
-use std::iter::Peekable;
-use std::vec::IntoIter;
 
-#[derive(PartialEq, Debug)]
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct Span {
+    pub start_line: u64,
+    pub start_col: u64,
+    pub end_line: u64,
+    pub end_col: u64,
+}
+
+// Owns the source's characters and tracks the read position (absolute
+// offset plus line/column), recording enough history to rewind. This
+// replaces the single-character `Peekable` lookahead the lexer started
+// with, which couldn't look more than one character ahead or undo a
+// speculative match — `handle_number`'s radix-literal scan uses `seek_back`
+// to bail out of the `r` prefix once it turns out not to be one.
+pub struct Cursor {
+    chars: Vec<char>,
+    offset: usize,
+    line: u64,
+    column: u64,
+    // The (line, column) in effect before each consumed character, so
+    // `seek_back` can restore them without rescanning from the start.
+    history: Vec<(u64, u64)>,
+}
+
+impl Cursor {
+    pub fn new(source: String) -> Cursor {
+        Cursor {
+            chars: source.chars().collect(),
+            offset: 0,
+            line: 1,
+            column: 1,
+            history: Vec::new(),
+        }
+    }
+
+    pub fn position(&self) -> (u64, u64) {
+        (self.line, self.column)
+    }
+
+    // Looks `n` characters past the next unread character without
+    // consuming anything. `peek_n(0)` is the next unread character.
+    pub fn peek_n(&self, n: usize) -> Option<char> {
+        self.chars.get(self.offset + n).copied()
+    }
+
+    pub fn peek(&self) -> Option<char> {
+        self.peek_n(0)
+    }
+
+    pub fn advance(&mut self) -> Option<char> {
+        let c = *self.chars.get(self.offset)?;
+        self.history.push((self.line, self.column));
+        self.offset += 1;
+
+        match c {
+            '\n' => {
+                self.line += 1;
+                self.column = 1;
+            }
+            '\r' => {}
+            _ => {
+                self.column += 1;
+            }
+        }
+
+        Some(c)
+    }
+
+    // Rewinds `n` characters, restoring both the read offset and the
+    // line/column that were in effect before they were consumed.
+    pub fn seek_back(&mut self, n: usize) {
+        assert!(
+            n <= self.history.len(),
+            "cannot seek back further than consumed history"
+        );
+
+        for _ in 0..n {
+            let (line, column) = self.history.pop().expect("checked history length above");
+            self.line = line;
+            self.column = column;
+        }
+
+        self.offset -= n;
+    }
+}
+
+#[derive(PartialEq, Debug, Clone, Copy)]
 pub enum Symbol {
     Identifier,
     Keyword,
@@ -17,37 +101,56 @@ pub enum Symbol {
     Period,
     Semicolon,
     Hash,
-    LT,
-    GT,
     StringLiteral,
     Comment,
     LBrace,
     RBrace,
     DollarSign,
     Bang,
+    Bar,
+    Assign,
+    BinarySelector,
     EndOfFile,
 }
 
+// Characters that make up binary selectors such as `+`, `<=` or `->`.
+// `|` is deliberately excluded: `parse_temporaries`'s `| x y |` grammar
+// depends on a lone `|` being its own dedicated `Symbol::Bar` token.
+const BINARY_SELECTOR_CHARS: &[char] =
+    &['+', '-', '*', '/', '<', '>', '=', '&', '@', '~'];
+
 pub struct Token {
     pub symbol: Symbol,
     pub value: String,
+    pub span: Span,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct LexerError {
     pub message: String,
+    pub span: Span,
+}
+
+impl LexerError {
+    // Renders a multi-line diagnostic pointing at the offending span in
+    // `source`, for callers (the REPL, a `.st` file runner) that want more
+    // than the bare `message`.
+    pub fn report(&self, source: &str, filename: &str) -> String {
+        crate::diagnostics::render(source, filename, self.span, &self.message)
+    }
 }
 
 pub struct LexerInstance {
-    reader_iter: Peekable<IntoIter<char>>,
-    column: u64,
-    line: u64,
+    cursor: Cursor,
+    done: bool,
+    peeked: Option<Option<Result<Token, LexerError>>>,
 }
 
-fn generate_token(symbol: Symbol, value: String) -> Token {
+fn generate_token(symbol: Symbol, value: String, span: Span) -> Token {
     Token {
         symbol,
         value,
+        span,
     }
 }
 
@@ -56,35 +159,68 @@ impl LexerInstance {
         let s = fs::read_to_string(filename)?;
 
         Ok(LexerInstance {
-            reader_iter: s.chars().collect::<Vec<_>>().into_iter().peekable(),
-            column: 1,
-            line: 1,
+            cursor: Cursor::new(s),
+            done: false,
+            peeked: None,
         })
     }
 
-    fn get_char(&mut self) -> Option<char> {
-        if let Some(c) = self.reader_iter.next() {
-            match c {
-                '\n' => {
-                    self.column = 1;
-                    self.line += 1;
-                    return Some(c);
-                }
-                '\r' => {
-                    return Some(c);
+    // Pulls the next non-comment token, or `None` once the stream is
+    // exhausted. `EndOfFile` is yielded exactly once so `LexerInstance` can
+    // be driven with a plain `for` loop instead of matching on the sentinel
+    // token forever.
+    fn raw_next(&mut self) -> Option<Result<Token, LexerError>> {
+        if self.done {
+            return None;
+        }
+
+        loop {
+            match self.next_token() {
+                Ok(token) => {
+                    if token.symbol == Symbol::Comment {
+                        continue;
+                    }
+                    if token.symbol == Symbol::EndOfFile {
+                        self.done = true;
+                    }
+                    return Some(Ok(token));
                 }
-                _ => {
-                    self.column += 1;
-                    return Some(c);
+                Err(err) => {
+                    self.done = true;
+                    return Some(Err(err));
                 }
             }
         }
+    }
+
+    // Looks at the next token without consuming it. Repeated calls return
+    // the same buffered token until `next` is called.
+    pub fn peek(&mut self) -> Option<&Result<Token, LexerError>> {
+        if self.peeked.is_none() {
+            self.peeked = Some(self.raw_next());
+        }
 
-        None
+        self.peeked.as_ref().unwrap().as_ref()
+    }
+
+    fn span_from(&self, start: (u64, u64)) -> Span {
+        let (start_line, start_col) = start;
+        let (end_line, end_col) = self.cursor.position();
+
+        Span {
+            start_line,
+            start_col,
+            end_line,
+            end_col,
+        }
+    }
+
+    fn get_char(&mut self) -> Option<char> {
+        self.cursor.advance()
     }
 
     fn skip_whitespace(&mut self) {
-        while let Some(&c) = self.reader_iter.peek() {
+        while let Some(c) = self.cursor.peek() {
             match c {
                 '\t' | '\r' | '\n' | ' ' => {
                     let _ = self.get_char();
@@ -97,34 +233,173 @@ impl LexerInstance {
     }
 
     fn handle_number(&mut self) -> Result<Token, LexerError> {
-        Ok(generate_token(Symbol::EndOfFile, "".to_string()))
+        let start = self.cursor.position();
+        let mut value = String::new();
+
+        while let Some(c) = self.cursor.peek() {
+            if c.is_ascii_digit() {
+                value.push(c);
+                let _ = self.get_char();
+            } else {
+                break;
+            }
+        }
+
+        // radix literal: <base>r<digits>, base in 2..=36, digits in 0-9A-Z.
+        // An invalid base means the 'r' never belonged to a radix literal at
+        // all, so that case backs the cursor up over the 'r' and lets the
+        // plain integer stand, with the 'r' re-lexed as the start of
+        // whatever comes next. A valid base with no digits after it, or a
+        // digit that exceeds the base, is a malformed radix literal and is
+        // a hard `LexerError` rather than a fallback.
+        if let Some('r') = self.cursor.peek() {
+            let base: u32 = value.parse().unwrap_or(0);
+            let _ = self.get_char();
+
+            if !(2..=36).contains(&base) {
+                self.cursor.seek_back(1);
+                return Ok(generate_token(Symbol::Number, value, self.span_from(start)));
+            }
+
+            let mut radix_digits = String::new();
+            while let Some(c) = self.cursor.peek() {
+                if c.is_ascii_digit() || c.is_ascii_uppercase() {
+                    let digit_value = c.to_digit(36).expect("checked ascii digit/uppercase");
+                    if digit_value >= base {
+                        return Err(LexerError {
+                            message: format!(
+                                "digit '{}' is out of range for radix {} literal: {}r{}",
+                                c, base, value, radix_digits
+                            ),
+                            span: self.span_from(start),
+                        });
+                    }
+                    radix_digits.push(c);
+                    let _ = self.get_char();
+                } else {
+                    break;
+                }
+            }
+
+            if radix_digits.is_empty() {
+                return Err(LexerError {
+                    message: format!("radix literal has no digits: {}r", value),
+                    span: self.span_from(start),
+                });
+            }
+
+            value.push('r');
+            value.push_str(&radix_digits);
+            return Ok(generate_token(Symbol::Number, value, self.span_from(start)));
+        }
+
+        // fractional part: only consume '.' when it is immediately followed
+        // by a digit, otherwise it belongs to the caller as Symbol::Period
+        let mut has_fraction = false;
+        if self.cursor.peek() == Some('.') {
+            if let Some(c) = self.cursor.peek_n(1) {
+                if c.is_ascii_digit() {
+                    has_fraction = true;
+                    value.push('.');
+                    let _ = self.get_char();
+
+                    while let Some(c) = self.cursor.peek() {
+                        if c.is_ascii_digit() {
+                            value.push(c);
+                            let _ = self.get_char();
+                        } else {
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+
+        // exponent notation: e<digits> or e-<digits>
+        let mut has_exponent = false;
+        if self.cursor.peek() == Some('e') {
+            let sign = self.cursor.peek_n(1);
+            let exponent_starts = match sign {
+                Some(c) if c.is_ascii_digit() => Some(1),
+                Some('-') if matches!(self.cursor.peek_n(2), Some(c) if c.is_ascii_digit()) => {
+                    Some(2)
+                }
+                _ => None,
+            };
+
+            if let Some(sign_width) = exponent_starts {
+                has_exponent = true;
+                value.push('e');
+                let _ = self.get_char();
+
+                if sign_width == 2 {
+                    value.push('-');
+                    let _ = self.get_char();
+                }
+
+                while let Some(c) = self.cursor.peek() {
+                    if c.is_ascii_digit() {
+                        value.push(c);
+                        let _ = self.get_char();
+                    } else {
+                        break;
+                    }
+                }
+            }
+        }
+
+        // scaled decimal: <digits>.<digits>s<scale>
+        if has_fraction && !has_exponent && self.cursor.peek() == Some('s') {
+            value.push('s');
+            let _ = self.get_char();
+
+            while let Some(c) = self.cursor.peek() {
+                if c.is_ascii_digit() {
+                    value.push(c);
+                    let _ = self.get_char();
+                } else {
+                    break;
+                }
+            }
+        }
+
+        Ok(generate_token(Symbol::Number, value, self.span_from(start)))
     }
 
     fn handle_string(&mut self) -> Result<Token, LexerError> {
+        let start = self.cursor.position();
         let _ = self
             .get_char()
             .expect("first character should be available");
         let mut value = String::new();
         // do NOT push the first ' into the result
 
-        while let Some(&c) = self.reader_iter.peek() {
+        while let Some(c) = self.cursor.peek() {
             match c {
                 '\'' => {
                     // this is either the end of the string or an escaped '
                     let _ = self.get_char();
 
-                    if let Some(&ch) = self.reader_iter.peek() {
+                    if let Some(ch) = self.cursor.peek() {
                         match ch {
                             '\'' => {
                                 value.push('\'');
                                 let _ = self.get_char();
                             }
                             _ => {
-                                return Ok(generate_token(Symbol::StringLiteral, value));
+                                return Ok(generate_token(
+                                    Symbol::StringLiteral,
+                                    value,
+                                    self.span_from(start),
+                                ));
                             }
                         }
                     } else {
-                        return Ok(generate_token(Symbol::StringLiteral, value));
+                        return Ok(generate_token(
+                            Symbol::StringLiteral,
+                            value,
+                            self.span_from(start),
+                        ));
                     }
                 }
                 _ => {
@@ -139,66 +414,263 @@ impl LexerInstance {
 
         Err(LexerError {
             message: format!("unterminated string constant: {}", value),
+            span: self.span_from(start),
         })
     }
 
     fn handle_alpha(&mut self) -> Result<Token, LexerError> {
+        let start = self.cursor.position();
         let first_ch = self
             .get_char()
             .expect("first character should be available");
         let mut value = String::new();
         value.push(first_ch);
 
-        while let Some(&c) = self.reader_iter.peek() {
+        while let Some(c) = self.cursor.peek() {
             match c {
-                '\t' | '\r' | '\n' | ' ' => {
-                    return Ok(generate_token(Symbol::Identifier, value));
-                }
                 'A'..='Z' | 'a'..='z' | '0'..='9' | '_' => {
                     let _ = self.get_char();
                     value.push(c);
                 }
                 ':' => {
                     let _ = self.get_char();
-                    return Ok(generate_token(Symbol::Keyword, value));
+                    return Ok(generate_token(Symbol::Keyword, value, self.span_from(start)));
                 }
+                // Anything else (whitespace, punctuation, a binary-selector
+                // char, ...) ends the identifier without being consumed, so
+                // `next_token` can re-dispatch on it.
                 _ => {
-                    return Err(LexerError {
-                        message: format!("unexpected char {}", c),
-                    });
+                    return Ok(generate_token(Symbol::Identifier, value, self.span_from(start)));
                 }
             }
         }
 
-        Ok(generate_token(Symbol::EndOfFile, value))
+        Ok(generate_token(Symbol::EndOfFile, value, self.span_from(start)))
     }
 
     fn handle_comment(&mut self) -> Result<Token, LexerError> {
-        Ok(generate_token(Symbol::EndOfFile, "".to_string()))
+        let start = self.cursor.position();
+        let _ = self
+            .get_char()
+            .expect("first character should be available");
+        let mut value = String::new();
+        // do NOT push the first " into the result
+
+        while let Some(c) = self.cursor.peek() {
+            match c {
+                '"' => {
+                    // this is either the end of the comment or an escaped "
+                    let _ = self.get_char();
+
+                    if let Some(ch) = self.cursor.peek() {
+                        match ch {
+                            '"' => {
+                                value.push('"');
+                                let _ = self.get_char();
+                            }
+                            _ => {
+                                return Ok(generate_token(
+                                    Symbol::Comment,
+                                    value,
+                                    self.span_from(start),
+                                ));
+                            }
+                        }
+                    } else {
+                        return Ok(generate_token(Symbol::Comment, value, self.span_from(start)));
+                    }
+                }
+                _ => {
+                    let _ = self.get_char();
+                    if c != '\r' {
+                        // strip any \r, we're all \n internally
+                        value.push(c);
+                    }
+                }
+            }
+        }
+
+        Err(LexerError {
+            message: format!("unterminated comment: {}", value),
+            span: self.span_from(start),
+        })
+    }
+
+    // `$x` character literals: the value following `$` is taken verbatim,
+    // including the `$` itself, mirroring the raw-text convention used by
+    // `handle_number`.
+    fn handle_character_literal(&mut self) -> Result<Token, LexerError> {
+        let start = self.cursor.position();
+        let mut value = String::new();
+        value.push(
+            self.get_char()
+                .expect("first character should be available"),
+        );
+
+        match self.get_char() {
+            Some(c) => {
+                value.push(c);
+                Ok(generate_token(Symbol::DollarSign, value, self.span_from(start)))
+            }
+            None => Err(LexerError {
+                message: "unterminated character literal".to_string(),
+                span: self.span_from(start),
+            }),
+        }
+    }
+
+    // `#symbol`, `#at:put:`, `#+` and the `#(` prefix of array literals.
+    // Only the `#` itself is consumed for `#(` — the parens are lexed as
+    // ordinary `LParen`/`RParen` tokens by the caller.
+    fn handle_hash(&mut self) -> Result<Token, LexerError> {
+        let start = self.cursor.position();
+        let mut value = String::new();
+        value.push(
+            self.get_char()
+                .expect("first character should be available"),
+        );
+
+        match self.cursor.peek() {
+            Some('(') => Ok(generate_token(Symbol::Hash, value, self.span_from(start))),
+            // `|` isn't in `BINARY_SELECTOR_CHARS` (it's reserved for
+            // `Symbol::Bar`), so the symbol-literal form needs its own arm.
+            Some('|') => {
+                value.push('|');
+                let _ = self.get_char();
+                Ok(generate_token(Symbol::Hash, value, self.span_from(start)))
+            }
+            Some(c) if c.is_ascii_alphabetic() || c == '_' => {
+                while let Some(c) = self.cursor.peek() {
+                    if c.is_ascii_alphanumeric() || c == '_' || c == ':' {
+                        value.push(c);
+                        let _ = self.get_char();
+                    } else {
+                        break;
+                    }
+                }
+                Ok(generate_token(Symbol::Hash, value, self.span_from(start)))
+            }
+            Some(c) if BINARY_SELECTOR_CHARS.contains(&c) => {
+                while let Some(c) = self.cursor.peek() {
+                    if BINARY_SELECTOR_CHARS.contains(&c) {
+                        value.push(c);
+                        let _ = self.get_char();
+                    } else {
+                        break;
+                    }
+                }
+                Ok(generate_token(Symbol::Hash, value, self.span_from(start)))
+            }
+            _ => Err(LexerError {
+                message: format!("malformed symbol literal: {}", value),
+                span: self.span_from(start),
+            }),
+        }
+    }
+
+    fn handle_binary_selector(&mut self) -> Result<Token, LexerError> {
+        let start = self.cursor.position();
+        let mut value = String::new();
+
+        while let Some(c) = self.cursor.peek() {
+            if BINARY_SELECTOR_CHARS.contains(&c) {
+                value.push(c);
+                let _ = self.get_char();
+            } else {
+                break;
+            }
+        }
+
+        Ok(generate_token(Symbol::BinarySelector, value, self.span_from(start)))
     }
 
     pub fn next_token(&mut self) -> Result<Token, LexerError> {
         self.skip_whitespace();
+        let start = self.cursor.position();
 
-        if let Some(&c) = self.reader_iter.peek() {
+        if let Some(c) = self.cursor.peek() {
             match c {
                 '0'..='9' => self.handle_number(),
                 'A'..='Z' | 'a'..='z' => self.handle_alpha(),
                 '.' => {
                     let _ = self.get_char();
-                    Ok(generate_token(Symbol::Period, ".".to_string()))
+                    Ok(generate_token(Symbol::Period, ".".to_string(), self.span_from(start)))
+                }
+                '|' => {
+                    let _ = self.get_char();
+                    Ok(generate_token(Symbol::Bar, "|".to_string(), self.span_from(start)))
+                }
+                ':' => {
+                    if self.cursor.peek_n(1) == Some('=') {
+                        let _ = self.get_char();
+                        let _ = self.get_char();
+                        Ok(generate_token(Symbol::Assign, ":=".to_string(), self.span_from(start)))
+                    } else {
+                        let _ = self.get_char();
+                        Err(LexerError {
+                            message: "unexpected character :".to_string(),
+                            span: self.span_from(start),
+                        })
+                    }
                 }
                 '\'' => self.handle_string(),
                 '"' => self.handle_comment(),
+                '$' => self.handle_character_literal(),
+                '#' => self.handle_hash(),
+                '(' => {
+                    let _ = self.get_char();
+                    Ok(generate_token(Symbol::LParen, "(".to_string(), self.span_from(start)))
+                }
+                ')' => {
+                    let _ = self.get_char();
+                    Ok(generate_token(Symbol::RParen, ")".to_string(), self.span_from(start)))
+                }
+                '[' => {
+                    let _ = self.get_char();
+                    Ok(generate_token(Symbol::LBracket, "[".to_string(), self.span_from(start)))
+                }
+                ']' => {
+                    let _ = self.get_char();
+                    Ok(generate_token(Symbol::RBracket, "]".to_string(), self.span_from(start)))
+                }
+                '{' => {
+                    let _ = self.get_char();
+                    Ok(generate_token(Symbol::LBrace, "{".to_string(), self.span_from(start)))
+                }
+                '}' => {
+                    let _ = self.get_char();
+                    Ok(generate_token(Symbol::RBrace, "}".to_string(), self.span_from(start)))
+                }
+                ';' => {
+                    let _ = self.get_char();
+                    Ok(generate_token(Symbol::Semicolon, ";".to_string(), self.span_from(start)))
+                }
+                '!' => {
+                    let _ = self.get_char();
+                    Ok(generate_token(Symbol::Bang, "!".to_string(), self.span_from(start)))
+                }
+                c if BINARY_SELECTOR_CHARS.contains(&c) => self.handle_binary_selector(),
                 _ => {
                     let _ = self.get_char();
                     Err(LexerError {
                         message: format!("unexpected character {}", c),
+                        span: self.span_from(start),
                     })
                 }
             }
         } else {
-            Ok(generate_token(Symbol::EndOfFile, "".to_string()))
+            Ok(generate_token(Symbol::EndOfFile, "".to_string(), self.span_from(start)))
+        }
+    }
+}
+
+impl Iterator for LexerInstance {
+    type Item = Result<Token, LexerError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.peeked.take() {
+            Some(token) => token,
+            None => self.raw_next(),
         }
     }
 }
@@ -207,6 +679,44 @@ impl LexerInstance {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_cursor_peek_n_and_advance() {
+        let mut cursor = Cursor::new("ab\ncd".to_string());
+
+        assert_eq!(Some('a'), cursor.peek());
+        assert_eq!(Some('b'), cursor.peek_n(1));
+        assert_eq!(Some('\n'), cursor.peek_n(2));
+        assert_eq!(None, cursor.peek_n(10));
+
+        assert_eq!((1, 1), cursor.position());
+        assert_eq!(Some('a'), cursor.advance());
+        assert_eq!((1, 2), cursor.position());
+        assert_eq!(Some('b'), cursor.advance());
+        assert_eq!(Some('\n'), cursor.advance());
+        assert_eq!((2, 1), cursor.position());
+    }
+
+    #[test]
+    fn test_cursor_seek_back_restores_position() {
+        let mut cursor = Cursor::new("ab\ncd".to_string());
+
+        let _ = cursor.advance(); // 'a'
+        let _ = cursor.advance(); // 'b'
+        let _ = cursor.advance(); // '\n', now on line 2
+        assert_eq!((2, 1), cursor.position());
+
+        cursor.seek_back(3);
+        assert_eq!((1, 1), cursor.position());
+        assert_eq!(Some('a'), cursor.peek());
+
+        // replaying after the rewind reaches the same state as before
+        let _ = cursor.advance();
+        let _ = cursor.advance();
+        let _ = cursor.advance();
+        assert_eq!((2, 1), cursor.position());
+        assert_eq!(Some('c'), cursor.peek());
+    }
+
     #[test]
     fn test_empty_source_file() {
         let result = LexerInstance::new("tests/empty.st".to_string());
@@ -261,4 +771,186 @@ mod tests {
         let token = instance.next_token().expect("unable to get token");
         assert_eq!(Symbol::EndOfFile, token.symbol);
     }
+
+    #[test]
+    fn test_numbers() {
+        let result = LexerInstance::new("tests/numbers.st".to_string());
+        assert!(result.is_ok());
+
+        let mut instance = result.unwrap();
+
+        let expected = [
+            "42", "3.14", "1.5e10", "2e-3", "16rFF", "2r1010", "3.14s2",
+        ];
+
+        for value in expected {
+            let token = instance.next_token().expect("unable to get token");
+            assert_eq!(Symbol::Number, token.symbol);
+            assert_eq!(value, token.value);
+
+            let token = instance.next_token().expect("unable to get token");
+            assert_eq!(Symbol::Period, token.symbol);
+        }
+
+        let token = instance.next_token().expect("unable to get token");
+        assert_eq!(Symbol::EndOfFile, token.symbol);
+    }
+
+    // An invalid base means the 'r' was never a radix literal in the first
+    // place, so the lexer backs the cursor up over it and re-lexes it as
+    // the start of the next token.
+    #[test]
+    fn test_radix_fallback_backtracks_over_invalid_base() {
+        let mut lexer = LexerInstance {
+            cursor: Cursor::new("999rFF ".to_string()),
+            done: false,
+            peeked: None,
+        };
+
+        let token = lexer.next_token().expect("unable to get token");
+        assert_eq!(Symbol::Number, token.symbol);
+        assert_eq!("999", token.value);
+
+        let token = lexer.next_token().expect("unable to get token");
+        assert_eq!(Symbol::Identifier, token.symbol);
+        assert_eq!("rFF", token.value);
+    }
+
+    // A valid base with no digits after it is a malformed radix literal,
+    // not a plain integer followed by something else, so it stays a hard
+    // error rather than falling back.
+    #[test]
+    fn test_radix_literal_with_no_digits_is_an_error() {
+        let mut lexer = LexerInstance {
+            cursor: Cursor::new("8rz ".to_string()),
+            done: false,
+            peeked: None,
+        };
+
+        match lexer.next_token() {
+            Err(err) => assert_eq!("radix literal has no digits: 8r", err.message),
+            Ok(_) => panic!("expected a LexerError"),
+        }
+    }
+
+    #[test]
+    fn test_peek_does_not_consume() {
+        let result = LexerInstance::new("tests/identifier.st".to_string());
+        assert!(result.is_ok());
+
+        let mut instance = result.unwrap();
+
+        let peeked = instance.peek().expect("unable to peek token");
+        assert!(peeked.is_ok());
+        assert_eq!("Foobar", peeked.as_ref().unwrap().value);
+
+        let token = instance.next().expect("unable to get token").unwrap();
+        assert_eq!(Symbol::EndOfFile, token.symbol);
+        assert_eq!("Foobar", token.value);
+
+        assert!(instance.next().is_none());
+    }
+
+    #[test]
+    fn test_iterator_stops_at_end_of_file() {
+        let result = LexerInstance::new("tests/strings.st".to_string());
+        assert!(result.is_ok());
+
+        let tokens: Vec<Token> = result
+            .unwrap()
+            .map(|t| t.expect("unable to get token"))
+            .collect();
+
+        assert_eq!(Symbol::EndOfFile, tokens.last().unwrap().symbol);
+        assert_eq!(
+            1,
+            tokens.iter().filter(|t| t.symbol == Symbol::EndOfFile).count()
+        );
+    }
+
+    #[test]
+    fn test_punctuation() {
+        let result = LexerInstance::new("tests/punctuation.st".to_string());
+        assert!(result.is_ok());
+
+        let mut instance = result.unwrap();
+
+        let expected = [
+            (Symbol::LParen, "("),
+            (Symbol::Identifier, "foo"),
+            (Symbol::RParen, ")"),
+            (Symbol::LBracket, "["),
+            (Symbol::Identifier, "bar"),
+            (Symbol::RBracket, "]"),
+            (Symbol::LBrace, "{"),
+            (Symbol::Identifier, "baz"),
+            (Symbol::RBrace, "}"),
+            (Symbol::Semicolon, ";"),
+            (Symbol::DollarSign, "$x"),
+            (Symbol::Hash, "#sym"),
+            (Symbol::Hash, "#at:put:"),
+            (Symbol::Hash, "#+"),
+            (Symbol::Number, "3"),
+            (Symbol::BinarySelector, "+"),
+            (Symbol::Number, "4"),
+            (Symbol::Number, "3"),
+            (Symbol::BinarySelector, "<="),
+            (Symbol::Number, "4"),
+            (Symbol::Number, "3"),
+            (Symbol::BinarySelector, ">="),
+            (Symbol::Number, "4"),
+            (Symbol::Hash, "#<="),
+            (Symbol::Hash, "#|"),
+        ];
+
+        for (symbol, value) in expected {
+            let token = instance.next_token().expect("unable to get token");
+            assert_eq!(symbol, token.symbol);
+            assert_eq!(value, token.value);
+        }
+
+        let token = instance.next_token().expect("unable to get token");
+        assert_eq!(Symbol::EndOfFile, token.symbol);
+    }
+
+    #[test]
+    fn test_comments() {
+        let result = LexerInstance::new("tests/comments.st".to_string());
+        assert!(result.is_ok());
+
+        let mut instance = result.unwrap();
+
+        let token = instance.next_token().expect("unable to get token");
+        assert_eq!(Symbol::Comment, token.symbol);
+        assert_eq!("a comment", token.value);
+
+        let token = instance.next_token().expect("unable to get token");
+        assert_eq!(Symbol::Identifier, token.symbol);
+        assert_eq!("foo", token.value);
+
+        let token = instance.next_token().expect("unable to get token");
+        assert_eq!(Symbol::Comment, token.symbol);
+        assert_eq!("a \"quoted\" comment\nspanning lines", token.value);
+
+        let token = instance.next_token().expect("unable to get token");
+        assert_eq!(Symbol::Identifier, token.symbol);
+        assert_eq!("bar", token.value);
+
+        let token = instance.next_token().expect("unable to get token");
+        assert_eq!(Symbol::EndOfFile, token.symbol);
+    }
+
+    #[test]
+    fn test_comments_are_filtered_by_the_iterator() {
+        let result = LexerInstance::new("tests/comments.st".to_string());
+        assert!(result.is_ok());
+
+        let tokens: Vec<Token> = result
+            .unwrap()
+            .map(|t| t.expect("unable to get token"))
+            .collect();
+
+        assert!(tokens.iter().all(|t| t.symbol != Symbol::Comment));
+        assert_eq!(3, tokens.len());
+    }
 }
@@ -4,7 +4,7 @@ use crate::lexer::*;
 
 #[allow(dead_code)]
 #[derive(Debug)]
-enum Node {
+pub enum Node {
     Empty,
     Expression,
     Identifier {
@@ -18,3 +18,250 @@ enum Node {
         variables: Vec<String>,
     },
 }
+
+#[derive(Debug)]
+pub enum ParseError {
+    UnexpectedToken { span: Span, message: String },
+    EndOfTokenStream,
+    Lexer(LexerError),
+}
+
+impl ParseError {
+    // Renders a multi-line diagnostic for any of the three ways parsing can
+    // fail. `EndOfTokenStream` has no span to point at, so it just reports
+    // the message.
+    pub fn report(&self, source: &str, filename: &str) -> String {
+        match self {
+            ParseError::UnexpectedToken { span, message } => {
+                crate::diagnostics::render(source, filename, *span, message)
+            }
+            ParseError::EndOfTokenStream => {
+                format!("{}: error: unexpected end of input\n", filename)
+            }
+            ParseError::Lexer(err) => err.report(source, filename),
+        }
+    }
+}
+
+pub struct Parser {
+    lexer: LexerInstance,
+    pending: Option<Token>,
+}
+
+impl Parser {
+    pub fn new(lexer: LexerInstance) -> Parser {
+        Parser {
+            lexer,
+            pending: None,
+        }
+    }
+
+    pub fn parse(&mut self) -> Result<Vec<Node>, ParseError> {
+        let mut nodes = Vec::new();
+
+        if self.peek_symbol()? == Some(Symbol::Bar) {
+            nodes.push(self.parse_temporaries()?);
+        }
+
+        while !self.at_end()? {
+            nodes.push(self.parse_statement()?);
+
+            if !self.accept(Symbol::Period)? {
+                break;
+            }
+        }
+
+        Ok(nodes)
+    }
+
+    fn parse_temporaries(&mut self) -> Result<Node, ParseError> {
+        self.expect(Symbol::Bar)?;
+
+        let mut variables = Vec::new();
+        while self.peek_symbol()? == Some(Symbol::Identifier) {
+            let token = self.advance()?;
+            variables.push(token.value);
+        }
+
+        self.expect(Symbol::Bar)?;
+        Ok(Node::Temporaries { variables })
+    }
+
+    fn parse_statement(&mut self) -> Result<Node, ParseError> {
+        let token = self.advance()?;
+
+        if token.symbol == Symbol::Identifier && self.peek_symbol()? == Some(Symbol::Assign) {
+            let variable = token.value;
+            let _ = self.advance()?; // consume ':='
+            let expression = self.parse_expression()?;
+
+            return Ok(Node::Assignment {
+                variable,
+                expression: Box::new(expression),
+            });
+        }
+
+        self.putback(token);
+        self.parse_expression()
+    }
+
+    // Minimal primary-expression parsing: the `Node` AST doesn't yet model
+    // literals or message sends, so anything that isn't a bare identifier
+    // collapses to `Node::Expression` as a placeholder for later work.
+    fn parse_expression(&mut self) -> Result<Node, ParseError> {
+        let token = self.advance()?;
+
+        match token.symbol {
+            Symbol::Identifier => Ok(Node::Identifier { name: token.value }),
+            _ => Ok(Node::Expression),
+        }
+    }
+
+    fn at_end(&mut self) -> Result<bool, ParseError> {
+        Ok(matches!(self.peek_symbol()?, None | Some(Symbol::EndOfFile)))
+    }
+
+    // The lexer tags a trailing identifier that runs right up to
+    // the real end of input as `Symbol::EndOfFile` (carrying the token's
+    // text) rather than `Symbol::Identifier`, since it only learns it has
+    // hit end-of-input while still scanning the token. Treat that case as
+    // the identifier it actually is rather than as genuine end-of-input.
+    fn effective_symbol(token: &Token) -> Symbol {
+        if token.symbol == Symbol::EndOfFile && !token.value.is_empty() {
+            Symbol::Identifier
+        } else {
+            token.symbol
+        }
+    }
+
+    // `peek_symbol` surfaces a buffered lexer error instead of mapping it
+    // to `None`, so callers that use it to decide whether input has run
+    // out (`at_end`, `accept`, the temporaries loop) don't mistake a lex
+    // error for a clean end-of-stream and silently drop it.
+    fn peek_symbol(&mut self) -> Result<Option<Symbol>, ParseError> {
+        if let Some(token) = &self.pending {
+            return Ok(Some(Self::effective_symbol(token)));
+        }
+
+        match self.lexer.peek() {
+            Some(Ok(token)) => Ok(Some(Self::effective_symbol(token))),
+            Some(Err(err)) => Err(ParseError::Lexer(err.clone())),
+            None => Ok(None),
+        }
+    }
+
+    fn advance(&mut self) -> Result<Token, ParseError> {
+        if let Some(mut token) = self.pending.take() {
+            token.symbol = Self::effective_symbol(&token);
+            return Ok(token);
+        }
+
+        match self.lexer.next() {
+            Some(Ok(mut token)) => {
+                token.symbol = Self::effective_symbol(&token);
+                Ok(token)
+            }
+            Some(Err(err)) => Err(ParseError::Lexer(err)),
+            None => Err(ParseError::EndOfTokenStream),
+        }
+    }
+
+    fn putback(&mut self, token: Token) {
+        debug_assert!(self.pending.is_none());
+        self.pending = Some(token);
+    }
+
+    fn expect(&mut self, symbol: Symbol) -> Result<Token, ParseError> {
+        let token = self.advance()?;
+
+        if token.symbol == symbol {
+            Ok(token)
+        } else {
+            Err(ParseError::UnexpectedToken {
+                span: token.span,
+                message: format!("expected {:?}, found {:?}", symbol, token.symbol),
+            })
+        }
+    }
+
+    fn accept(&mut self, symbol: Symbol) -> Result<bool, ParseError> {
+        if self.peek_symbol()? == Some(symbol) {
+            self.advance()?;
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_temporaries_and_assignment() {
+        let lexer = LexerInstance::new("tests/assignment.st".to_string())
+            .expect("unable to open fixture");
+        let mut parser = Parser::new(lexer);
+
+        let nodes = parser.parse().expect("unable to parse");
+        assert_eq!(2, nodes.len());
+
+        match &nodes[0] {
+            Node::Temporaries { variables } => {
+                assert_eq!(vec!["x".to_string(), "y".to_string()], *variables);
+            }
+            other => panic!("expected Temporaries, got {:?}", other),
+        }
+
+        match &nodes[1] {
+            Node::Assignment {
+                variable,
+                expression,
+            } => {
+                assert_eq!("x", variable);
+                match expression.as_ref() {
+                    Node::Identifier { name } => assert_eq!("y", name),
+                    other => panic!("expected Identifier, got {:?}", other),
+                }
+            }
+            other => panic!("expected Assignment, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_lexer_error_at_loop_boundary_is_propagated() {
+        let lexer = LexerInstance::new("tests/invalid_radix_digit.st".to_string())
+            .expect("unable to open fixture");
+        let mut parser = Parser::new(lexer);
+
+        match parser.parse() {
+            Err(ParseError::Lexer(_)) => {}
+            other => panic!("expected ParseError::Lexer, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_trailing_identifier_without_separator_is_not_dropped() {
+        let lexer = LexerInstance::new("tests/trailing_identifier.st".to_string())
+            .expect("unable to open fixture");
+        let mut parser = Parser::new(lexer);
+
+        let nodes = parser.parse().expect("unable to parse");
+        assert_eq!(2, nodes.len());
+
+        match &nodes[1] {
+            Node::Assignment {
+                variable,
+                expression,
+            } => {
+                assert_eq!("x", variable);
+                match expression.as_ref() {
+                    Node::Identifier { name } => assert_eq!("y", name),
+                    other => panic!("expected Identifier, got {:?}", other),
+                }
+            }
+            other => panic!("expected Assignment, got {:?}", other),
+        }
+    }
+}